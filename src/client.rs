@@ -1,20 +1,51 @@
 use crate::error::VaultError;
-use crate::schema::{VaultSchemaV1, VaultSchemaV2};
-
+use crate::schema::{
+    AppRoleLoginRequest, Auth, Data, JwtLoginRequest, KubernetesLoginRequest, TransitDecryptData,
+    TransitDecryptRequest, TransitEncryptData, TransitEncryptRequest, VaultLoginResponse,
+    VaultSchemaV1, VaultSchemaV2,
+};
+use crate::sensitive::SensitiveString;
+
+use base64::Engine;
 use bytes::{Buf, Bytes};
-use http_body_util::{BodyExt, Empty};
+use http_body_util::{BodyExt, Full};
 use hyper::{Request, StatusCode, Uri};
 use hyper_rustls::HttpsConnectorBuilder;
 use hyper_util::client::legacy::{Client, connect::HttpConnector};
 use hyper_util::rt::{TokioExecutor, TokioTimer};
+use rustls::{ClientConfig, RootCertStore};
+use serde::Serialize;
 use serde::de::DeserializeOwned;
 use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::RwLock;
 use tokio::time::Duration;
 use tracing::{error, info};
 
+/// Authentication method used to obtain a Vault client token during [`VaultBuilder::build`].
+///
+/// Set via [`VaultBuilder::app_role`], [`VaultBuilder::kubernetes`], or [`VaultBuilder::jwt`].
+/// When none of these are set, the builder falls back to the static `token`/`VAULT_TOKEN` value.
+#[derive(Debug, Clone)]
+pub enum AuthMethod {
+    AppRole {
+        role_id: String,
+        secret_id: SensitiveString,
+    },
+    Kubernetes {
+        role: String,
+        jwt_path: String,
+    },
+    Jwt {
+        role: String,
+        token: SensitiveString,
+    },
+}
+
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 type VaultApiResult = std::result::Result<(), Box<dyn std::error::Error + Send + Sync>>;
-type VaultHttpClient = Client<hyper_rustls::HttpsConnector<HttpConnector>, Empty<Bytes>>;
+type VaultHttpClient = Client<hyper_rustls::HttpsConnector<HttpConnector>, Full<Bytes>>;
 
 /// Represents a client to interact with HashiCorp Vault using HTTP(S).
 ///
@@ -24,8 +55,10 @@ pub struct Vault {
     secret_path: Option<String>,
     address: Option<String>,
     port: Option<u16>,
-    token: Option<String>,
+    token: Arc<RwLock<SensitiveString>>,
     protocol: Option<String>,
+    lease_duration: Option<u32>,
+    renewable: bool,
 }
 
 /// Builder for constructing a `Vault` client instance with custom or environment-configured options.
@@ -33,8 +66,11 @@ pub struct VaultBuilder {
     secret_path: Option<String>,
     address: Option<String>,
     port: Option<u16>,
-    token: Option<String>,
+    token: Option<SensitiveString>,
     protocol: Option<String>,
+    auth_method: Option<AuthMethod>,
+    ca_cert_path: Option<PathBuf>,
+    danger_accept_invalid_certs: bool,
 }
 
 impl VaultBuilder {
@@ -49,7 +85,7 @@ impl VaultBuilder {
     }
 
     pub fn token(&mut self, token: &str) -> &mut Self {
-        self.token = Some(token.to_string());
+        self.token = Some(SensitiveString::from(token));
         self
     }
 
@@ -68,6 +104,54 @@ impl VaultBuilder {
         self
     }
 
+    /// Authenticates via the AppRole auth method, exchanging `role_id`/`secret_id` for a
+    /// client token during `build()` instead of using a static token.
+    pub fn app_role(&mut self, role_id: &str, secret_id: &str) -> &mut Self {
+        self.auth_method = Some(AuthMethod::AppRole {
+            role_id: role_id.to_string(),
+            secret_id: SensitiveString::from(secret_id),
+        });
+        self
+    }
+
+    /// Authenticates via the Kubernetes auth method, using the service account JWT read from
+    /// `jwt_path` (typically `/var/run/secrets/kubernetes.io/serviceaccount/token`) to log in
+    /// as `role` during `build()`.
+    pub fn kubernetes(&mut self, role: &str, jwt_path: &str) -> &mut Self {
+        self.auth_method = Some(AuthMethod::Kubernetes {
+            role: role.to_string(),
+            jwt_path: jwt_path.to_string(),
+        });
+        self
+    }
+
+    /// Authenticates via the JWT auth method, exchanging `token` (the external JWT) for a
+    /// Vault client token as `role` during `build()`.
+    pub fn jwt(&mut self, role: &str, token: &str) -> &mut Self {
+        self.auth_method = Some(AuthMethod::Jwt {
+            role: role.to_string(),
+            token: SensitiveString::from(token),
+        });
+        self
+    }
+
+    /// Trusts the PEM-encoded CA certificate at `path` when verifying the Vault server's TLS
+    /// certificate, instead of the default webpki root store. Use this for private Vault
+    /// deployments signed by an internal CA.
+    pub fn ca_cert_pem(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        self.ca_cert_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Disables TLS certificate verification entirely.
+    ///
+    /// Dangerous: only use this against a Vault server you control, such as local development
+    /// against a self-signed certificate.
+    pub fn danger_accept_invalid_certs(&mut self) -> &mut Self {
+        self.danger_accept_invalid_certs = true;
+        self
+    }
+
     /// Builds the `Vault` client using provided values or environment variables.
     ///
     /// Environment fallbacks:
@@ -83,6 +167,16 @@ impl VaultBuilder {
             env::var("VAULT_SECRET_PATH").expect("Set secret_path or VAULT_SECRET_PATH")
         });
 
+        if self.address.is_none() && self.port.is_none() && self.protocol.is_none() {
+            if let Ok(vault_addr) = env::var("VAULT_ADDR") {
+                if let Ok(uri) = vault_addr.parse::<Uri>() {
+                    self.protocol = uri.scheme_str().map(str::to_string);
+                    self.address = uri.host().map(str::to_string);
+                    self.port = uri.port_u16();
+                }
+            }
+        }
+
         self.address.get_or_insert_with(|| {
             env::var("VAULT_ADDRESS").unwrap_or_else(|_| "127.0.0.1".to_string())
         });
@@ -94,31 +188,87 @@ impl VaultBuilder {
                 .unwrap()
         });
 
-        self.token
-            .get_or_insert_with(|| env::var("VAULT_TOKEN").expect("Set token or VAULT_TOKEN env"));
+        if self.auth_method.is_none() {
+            self.token.get_or_insert_with(|| {
+                SensitiveString::from(
+                    env::var("VAULT_TOKEN")
+                        .expect("Set token, an auth method, or VAULT_TOKEN env"),
+                )
+            });
+        }
 
         self.protocol.get_or_insert_with(|| {
             env::var("VAULT_PROTOCOL").unwrap_or_else(|_| "http".to_string())
         });
 
-        let https_connector = HttpsConnectorBuilder::new()
-            .with_webpki_roots()
-            .https_or_http()
-            .enable_http1()
-            .build();
+        let https_connector = if self.danger_accept_invalid_certs {
+            let tls_config = ClientConfig::builder()
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(NoCertificateVerification))
+                .with_no_client_auth();
+
+            HttpsConnectorBuilder::new()
+                .with_tls_config(tls_config)
+                .https_or_http()
+                .enable_http1()
+                .build()
+        } else if let Some(ca_cert_path) = &self.ca_cert_path {
+            let pem = tokio::fs::read(ca_cert_path).await?;
+            let mut root_store = RootCertStore::empty();
+            for cert in rustls_pemfile::certs(&mut pem.as_slice()) {
+                root_store.add(cert?)?;
+            }
+
+            let tls_config = ClientConfig::builder()
+                .with_root_certificates(root_store)
+                .with_no_client_auth();
+
+            HttpsConnectorBuilder::new()
+                .with_tls_config(tls_config)
+                .https_or_http()
+                .enable_http1()
+                .build()
+        } else {
+            HttpsConnectorBuilder::new()
+                .with_webpki_roots()
+                .https_or_http()
+                .enable_http1()
+                .build()
+        };
 
         let client = Client::builder(TokioExecutor::new())
             .pool_timer(TokioTimer::new())
             .pool_idle_timeout(Duration::from_secs(30))
             .build(https_connector);
 
+        let (token, lease_duration, renewable) = match &self.auth_method {
+            Some(method) => {
+                let auth = login(
+                    &client,
+                    self.protocol.as_ref().unwrap(),
+                    self.address.as_ref().unwrap(),
+                    self.port.unwrap(),
+                    method,
+                )
+                .await?;
+                (
+                    SensitiveString::from(auth.client_token),
+                    Some(auth.lease_duration),
+                    auth.renewable,
+                )
+            }
+            None => (self.token.clone().unwrap(), None, false),
+        };
+
         let vault = Vault {
             http_client: client,
             secret_path: self.secret_path.clone(),
             address: self.address.clone(),
             port: self.port,
-            token: self.token.clone(),
+            token: Arc::new(RwLock::new(token)),
             protocol: self.protocol.clone(),
+            lease_duration,
+            renewable,
         };
 
         vault.health_check().await?;
@@ -140,7 +290,60 @@ impl Vault {
             port: None,
             token: None,
             protocol: None,
+            auth_method: None,
+            ca_cert_path: None,
+            danger_accept_invalid_certs: false,
+        }
+    }
+
+    /// Parses a single connection string into a [`VaultBuilder`], as an alternative to calling
+    /// each fine-grained setter individually.
+    ///
+    /// Accepts `hashivault://<token>@<host>:<port>/<secret_path>` connection strings (the
+    /// `<token>@` and `:<port>` segments are optional), as well as a bare `VAULT_ADDR`-style
+    /// `https://<host>:<port>` address. When the scheme isn't `http`/`https`, the protocol is
+    /// inferred: `localhost`/`127.0.0.1` default to `http`, everything else to `https`. The
+    /// returned builder's setters can still be chained afterwards to override any value parsed
+    /// from the string.
+    pub fn from_uri(uri: &str) -> Result<VaultBuilder> {
+        let uri = uri.parse::<Uri>()?;
+        let mut builder = Vault::new();
+
+        let authority = uri
+            .authority()
+            .ok_or("vault connection string is missing a host")?
+            .as_str();
+
+        // `Uri` has no dedicated userinfo accessor, so pull it off the raw authority text
+        // ourselves; `host()`/`port_u16()` below already parse it correctly per RFC 3986. Note
+        // `host()` keeps the brackets around an IPv6 literal (e.g. `[::1]`), which is exactly
+        // the form needed to rebuild a `host:port` authority elsewhere in this module.
+        if let Some((userinfo, _)) = authority.rsplit_once('@') {
+            builder.token(userinfo);
         }
+
+        let host = uri
+            .host()
+            .ok_or("vault connection string is missing a host")?;
+        builder.address(host);
+
+        if let Some(port) = uri.port_u16() {
+            builder.port(port);
+        }
+
+        if let Some(secret_path) = uri.path().strip_prefix('/').filter(|p| !p.is_empty()) {
+            builder.secret_path(secret_path);
+        }
+
+        let protocol = match uri.scheme_str() {
+            Some("https") => "https",
+            Some("http") => "http",
+            _ if host == "localhost" || host == "127.0.0.1" => "http",
+            _ => "https",
+        };
+        builder.protocol(protocol);
+
+        Ok(builder)
     }
 
     // Performs a health check against the configured Vault server.
@@ -193,12 +396,13 @@ impl Vault {
         )
         .parse::<Uri>()?;
 
+        let token = self.token.read().await;
         let req = Request::builder()
             .method("GET")
             .uri(url)
             .header("content-type", "application/json")
-            .header("X-Vault-Token", self.token.as_ref().unwrap())
-            .body(Empty::<Bytes>::new())?;
+            .header("X-Vault-Token", token.expose())
+            .body(Full::<Bytes>::default())?;
 
         let res = self.http_client.request(req).await?;
         check_vault_error(res.status())
@@ -217,12 +421,13 @@ impl Vault {
         )
         .parse::<Uri>()?;
 
+        let token = self.token.read().await;
         let req = Request::builder()
             .method("GET")
             .uri(uri)
             .header("content-type", "application/json")
-            .header("X-Vault-Token", self.token.as_ref().unwrap())
-            .body(Empty::<Bytes>::new())?;
+            .header("X-Vault-Token", token.expose())
+            .body(Full::<Bytes>::default())?;
 
         let res = self.http_client.request(req).await?;
         check_vault_error(res.status())?;
@@ -246,12 +451,13 @@ impl Vault {
         )
         .parse::<Uri>()?;
 
+        let token = self.token.read().await;
         let req = Request::builder()
             .method("GET")
             .uri(uri)
             .header("content-type", "application/json")
-            .header("X-Vault-Token", self.token.as_ref().unwrap())
-            .body(Empty::<Bytes>::new())?;
+            .header("X-Vault-Token", token.expose())
+            .body(Full::<Bytes>::default())?;
 
         let res = self.http_client.request(req).await?;
         check_vault_error(res.status())?;
@@ -261,11 +467,401 @@ impl Vault {
             serde_json::from_reader(res.collect().await?.aggregate().reader())?;
         Ok(secret.data.data)
     }
+
+    /// Writes `data` as a new version of the KV v2 secret at the configured `secret_path`.
+    pub async fn write_secret<T>(&self, data: &T) -> VaultApiResult
+    where
+        T: Serialize,
+    {
+        let uri = format!(
+            "{}://{}:{}/v1/{}",
+            self.protocol.as_ref().unwrap(),
+            self.address.as_ref().unwrap(),
+            self.port.unwrap(),
+            self.secret_path.as_ref().unwrap()
+        )
+        .parse::<Uri>()?;
+
+        let body = serde_json::to_vec(&Data { data })?;
+
+        let token = self.token.read().await;
+        let req = Request::builder()
+            .method("PUT")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("X-Vault-Token", token.expose())
+            .body(Full::from(Bytes::from(body)))?;
+
+        let res = self.http_client.request(req).await?;
+        check_vault_error(res.status())?;
+        info!("Wrote secret v2 to vault");
+
+        Ok(())
+    }
+
+    /// Soft-deletes the latest version(s) of the KV v2 secret at the configured `secret_path`.
+    ///
+    /// The underlying data is retained and can be brought back with Vault's `undelete`
+    /// endpoint; use [`Vault::destroy_secret`] to permanently remove it.
+    pub async fn delete_secret(&self) -> VaultApiResult {
+        let uri = format!(
+            "{}://{}:{}/v1/{}",
+            self.protocol.as_ref().unwrap(),
+            self.address.as_ref().unwrap(),
+            self.port.unwrap(),
+            self.secret_path.as_ref().unwrap()
+        )
+        .parse::<Uri>()?;
+
+        let token = self.token.read().await;
+        let req = Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("X-Vault-Token", token.expose())
+            .body(Full::<Bytes>::default())?;
+
+        let res = self.http_client.request(req).await?;
+        check_vault_error(res.status())?;
+        info!("Deleted secret v2 from vault");
+
+        Ok(())
+    }
+
+    /// Permanently destroys all versions and metadata of the KV v2 secret at the configured
+    /// `secret_path` by DELETEing its `metadata` path (the data mount segment swapped for
+    /// `metadata`, e.g. `secret/data/foo` becomes `secret/metadata/foo`). This cannot be undone.
+    pub async fn destroy_secret(&self) -> VaultApiResult {
+        let metadata_path = self
+            .secret_path
+            .as_ref()
+            .unwrap()
+            .replacen("/data/", "/metadata/", 1);
+
+        let uri = format!(
+            "{}://{}:{}/v1/{}",
+            self.protocol.as_ref().unwrap(),
+            self.address.as_ref().unwrap(),
+            self.port.unwrap(),
+            metadata_path
+        )
+        .parse::<Uri>()?;
+
+        let token = self.token.read().await;
+        let req = Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("X-Vault-Token", token.expose())
+            .body(Full::<Bytes>::default())?;
+
+        let res = self.http_client.request(req).await?;
+        check_vault_error(res.status())?;
+        info!("Destroyed secret v2 in vault");
+
+        Ok(())
+    }
+
+    /// Encrypts `plaintext` using the Transit secrets engine key `key_name`.
+    ///
+    /// Sends the data base64-encoded to `/v1/transit/encrypt/{key_name}` and returns the
+    /// Vault-formatted ciphertext (e.g. `vault:v1:...`), letting callers encrypt data without
+    /// ever holding the underlying key material themselves.
+    pub async fn encrypt(&self, key_name: &str, plaintext: &[u8]) -> Result<String> {
+        let uri = format!(
+            "{}://{}:{}/v1/transit/encrypt/{}",
+            self.protocol.as_ref().unwrap(),
+            self.address.as_ref().unwrap(),
+            self.port.unwrap(),
+            key_name
+        )
+        .parse::<Uri>()?;
+
+        let body = serde_json::to_vec(&TransitEncryptRequest {
+            plaintext: base64::engine::general_purpose::STANDARD.encode(plaintext),
+        })?;
+
+        let token = self.token.read().await;
+        let req = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("X-Vault-Token", token.expose())
+            .body(Full::from(Bytes::from(body)))?;
+
+        let res = self.http_client.request(req).await?;
+        check_vault_error(res.status())?;
+        info!("Encrypted data with transit key {}", key_name);
+
+        let encrypted: VaultSchemaV1<TransitEncryptData> =
+            serde_json::from_reader(res.collect().await?.aggregate().reader())?;
+        Ok(encrypted.data.ciphertext)
+    }
+
+    /// Decrypts `ciphertext` (a Vault-formatted string such as `vault:v1:...`) using the
+    /// Transit secrets engine key `key_name`, returning the raw plaintext bytes.
+    pub async fn decrypt(&self, key_name: &str, ciphertext: &str) -> Result<Vec<u8>> {
+        let uri = format!(
+            "{}://{}:{}/v1/transit/decrypt/{}",
+            self.protocol.as_ref().unwrap(),
+            self.address.as_ref().unwrap(),
+            self.port.unwrap(),
+            key_name
+        )
+        .parse::<Uri>()?;
+
+        let body = serde_json::to_vec(&TransitDecryptRequest {
+            ciphertext: ciphertext.to_string(),
+        })?;
+
+        let token = self.token.read().await;
+        let req = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("X-Vault-Token", token.expose())
+            .body(Full::from(Bytes::from(body)))?;
+
+        let res = self.http_client.request(req).await?;
+        check_vault_error(res.status())?;
+        info!("Decrypted data with transit key {}", key_name);
+
+        let decrypted: VaultSchemaV1<TransitDecryptData> =
+            serde_json::from_reader(res.collect().await?.aggregate().reader())?;
+        Ok(base64::engine::general_purpose::STANDARD.decode(decrypted.data.plaintext)?)
+    }
+
+    /// Spawns a background task that keeps this client's token lease alive.
+    ///
+    /// The task wakes up at roughly 2/3 of the current `lease_duration`, renews the lease via
+    /// `/v1/auth/token/renew-self`, and writes the refreshed token back into the shared,
+    /// interior-mutable token state so in-flight and future requests pick it up automatically.
+    /// Returns `None` if this client has no renewable lease: it was built from a static
+    /// `token`/`VAULT_TOKEN` rather than an [`AuthMethod`] login, or Vault marked the login's
+    /// token as non-renewable (e.g. batch tokens).
+    ///
+    /// Call [`RenewalHandle::cancel`] on the returned handle to stop renewal on shutdown;
+    /// dropping the handle leaves the task running.
+    pub fn spawn_renewer(&self) -> Option<RenewalHandle> {
+        if !self.renewable {
+            return None;
+        }
+        let mut lease_duration = self.lease_duration?;
+
+        let http_client = self.http_client.clone();
+        let protocol = self.protocol.clone().unwrap();
+        let address = self.address.clone().unwrap();
+        let port = self.port.unwrap();
+        let token = Arc::clone(&self.token);
+
+        let (failed_tx, failed_rx) = tokio::sync::watch::channel(false);
+
+        let task = tokio::spawn(async move {
+            loop {
+                let renew_after = Duration::from_secs(lease_duration as u64 * 2 / 3);
+                tokio::time::sleep(renew_after).await;
+
+                let current_token = token.read().await.expose().to_string();
+                let mut renewed = None;
+                let mut retry_delay = Duration::from_secs(1);
+
+                for attempt in 1..=RENEW_MAX_ATTEMPTS {
+                    match renew_self(&http_client, &protocol, &address, port, &current_token).await
+                    {
+                        Ok(auth) => {
+                            renewed = Some(auth);
+                            break;
+                        }
+                        Err(err) if attempt < RENEW_MAX_ATTEMPTS => {
+                            error!(
+                                "Failed to renew vault token lease (attempt {}/{}): {}; retrying in {:?}",
+                                attempt, RENEW_MAX_ATTEMPTS, err, retry_delay
+                            );
+                            tokio::time::sleep(retry_delay).await;
+                            retry_delay *= 2;
+                        }
+                        Err(err) => {
+                            error!(
+                                "Failed to renew vault token lease after {} attempts, giving up: {}",
+                                RENEW_MAX_ATTEMPTS, err
+                            );
+                        }
+                    }
+                }
+
+                match renewed {
+                    Some(auth) => {
+                        *token.write().await = SensitiveString::from(auth.client_token);
+                        lease_duration = auth.lease_duration;
+                        info!("Renewed vault token lease");
+                    }
+                    None => {
+                        let _ = failed_tx.send(true);
+                        break;
+                    }
+                }
+            }
+        });
+
+        Some(RenewalHandle {
+            task,
+            failed: failed_rx,
+        })
+    }
+}
+
+/// Number of attempts `spawn_renewer`'s background task makes to renew the lease before giving
+/// up, with an exponential backoff (1s, 2s, 4s, ...) between attempts.
+const RENEW_MAX_ATTEMPTS: u32 = 4;
+
+/// Handle to a background token-renewal task spawned by [`Vault::spawn_renewer`].
+///
+/// Dropping this handle does not stop the task; call [`RenewalHandle::cancel`] to do that
+/// explicitly on shutdown. If renewal fails [`RENEW_MAX_ATTEMPTS`] times in a row, the task gives
+/// up and exits; [`RenewalHandle::renewal_failed`] lets callers notice and react (e.g. re-login
+/// and rebuild the client) instead of finding out only once the token has already expired.
+pub struct RenewalHandle {
+    task: tokio::task::JoinHandle<()>,
+    failed: tokio::sync::watch::Receiver<bool>,
+}
+
+impl RenewalHandle {
+    /// Cancels the background renewal task.
+    pub fn cancel(self) {
+        self.task.abort();
+    }
+
+    /// Returns `true` once the renewal task has exhausted its retries and stopped running.
+    pub fn renewal_failed(&self) -> bool {
+        *self.failed.borrow()
+    }
+}
+
+/// A [`rustls::client::danger::ServerCertVerifier`] that accepts any certificate.
+///
+/// Only constructed via [`VaultBuilder::danger_accept_invalid_certs`].
+#[derive(Debug)]
+struct NoCertificateVerification;
+
+impl rustls::client::danger::ServerCertVerifier for NoCertificateVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &rustls::pki_types::CertificateDer<'_>,
+        _intermediates: &[rustls::pki_types::CertificateDer<'_>],
+        _server_name: &rustls::pki_types::ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: rustls::pki_types::UnixTime,
+    ) -> std::result::Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        Ok(rustls::client::danger::ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        _message: &[u8],
+        _cert: &rustls::pki_types::CertificateDer<'_>,
+        _dss: &rustls::DigitallySignedStruct,
+    ) -> std::result::Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        _message: &[u8],
+        _cert: &rustls::pki_types::CertificateDer<'_>,
+        _dss: &rustls::DigitallySignedStruct,
+    ) -> std::result::Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        rustls::crypto::ring::default_provider()
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+/// Logs in via `method` against `/v1/auth/{mount}/login` and returns the resulting `auth` block.
+async fn login(
+    client: &VaultHttpClient,
+    protocol: &str,
+    address: &str,
+    port: u16,
+    method: &AuthMethod,
+) -> Result<Auth> {
+    let (mount, body) = match method {
+        AuthMethod::AppRole { role_id, secret_id } => (
+            "approle",
+            serde_json::to_vec(&AppRoleLoginRequest {
+                role_id: role_id.clone(),
+                secret_id: secret_id.expose().to_string(),
+            })?,
+        ),
+        AuthMethod::Kubernetes { role, jwt_path } => {
+            let jwt = tokio::fs::read_to_string(jwt_path).await?;
+            (
+                "kubernetes",
+                serde_json::to_vec(&KubernetesLoginRequest {
+                    role: role.clone(),
+                    jwt: jwt.trim().to_string(),
+                })?,
+            )
+        }
+        AuthMethod::Jwt { role, token } => (
+            "jwt",
+            serde_json::to_vec(&JwtLoginRequest {
+                role: role.clone(),
+                jwt: token.expose().to_string(),
+            })?,
+        ),
+    };
+
+    let uri = format!("{protocol}://{address}:{port}/v1/auth/{mount}/login").parse::<Uri>()?;
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Full::from(Bytes::from(body)))?;
+
+    let res = client.request(req).await?;
+    check_vault_error(res.status())?;
+    info!("Logged in to vault via {} auth method", mount);
+
+    let login_response: VaultLoginResponse =
+        serde_json::from_reader(res.collect().await?.aggregate().reader())?;
+    Ok(login_response.auth)
+}
+
+/// Renews the current token's lease via `/v1/auth/token/renew-self`, returning the refreshed
+/// `auth` block (new token value and lease duration).
+async fn renew_self(
+    client: &VaultHttpClient,
+    protocol: &str,
+    address: &str,
+    port: u16,
+    token: &str,
+) -> Result<Auth> {
+    let uri = format!("{protocol}://{address}:{port}/v1/auth/token/renew-self").parse::<Uri>()?;
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .header("X-Vault-Token", token)
+        .body(Full::<Bytes>::default())?;
+
+    let res = client.request(req).await?;
+    check_vault_error(res.status())?;
+
+    let renew_response: VaultLoginResponse =
+        serde_json::from_reader(res.collect().await?.aggregate().reader())?;
+    Ok(renew_response.auth)
 }
 
 fn check_vault_error(status_code: StatusCode) -> VaultApiResult {
     match status_code.as_u16() {
         200 => Ok(()),
+        204 => Ok(()),
         503 => {
             let err = Box::new(VaultError::VaultSealed(status_code));
             error!("{}", err.to_string());
@@ -303,3 +899,43 @@ fn check_vault_error(status_code: StatusCode) -> VaultApiResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_uri_parses_token_host_port_and_path() {
+        let builder =
+            Vault::from_uri("hashivault://s.abcd1234@127.0.0.1:8200/secret/data/foo").unwrap();
+        assert_eq!(builder.token.as_ref().unwrap().expose(), "s.abcd1234");
+        assert_eq!(builder.address.as_deref(), Some("127.0.0.1"));
+        assert_eq!(builder.port, Some(8200));
+        assert_eq!(builder.secret_path.as_deref(), Some("secret/data/foo"));
+        assert_eq!(builder.protocol.as_deref(), Some("http"));
+    }
+
+    #[test]
+    fn from_uri_infers_https_for_non_localhost() {
+        let builder = Vault::from_uri("hashivault://vault.example.com/secret/data/foo").unwrap();
+        assert_eq!(builder.protocol.as_deref(), Some("https"));
+    }
+
+    #[test]
+    fn from_uri_respects_explicit_scheme() {
+        let builder = Vault::from_uri("https://vault.internal:8200").unwrap();
+        assert_eq!(builder.protocol.as_deref(), Some("https"));
+        assert_eq!(builder.address.as_deref(), Some("vault.internal"));
+        assert_eq!(builder.port, Some(8200));
+    }
+
+    #[test]
+    fn from_uri_supports_bracketed_ipv6_host_without_port() {
+        let builder = Vault::from_uri("hashivault://token@[::1]/secret/path").unwrap();
+        // `Authority::host()` keeps the brackets for an IPv6 literal; that's the form needed
+        // to rebuild a valid `host:port` authority, so we keep it as-is rather than stripping it.
+        assert_eq!(builder.address.as_deref(), Some("[::1]"));
+        assert_eq!(builder.port, None);
+        assert_eq!(builder.token.as_ref().unwrap().expose(), "token");
+    }
+}
@@ -1,9 +1,11 @@
+use crate::sensitive::SensitiveString;
+
 /// Config struct to match the address of vault
 pub struct Config {
     pub address: String,
     pub port: u16,
     pub config_path: String,
-    pub token: String,
+    pub token: SensitiveString,
     pub protocol: String,
 }
 
@@ -19,7 +21,7 @@ impl Config {
             address,
             port,
             config_path,
-            token,
+            token: SensitiveString::from(token),
             protocol,
         }
     }
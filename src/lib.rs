@@ -40,4 +40,7 @@ pub mod client;
 pub mod error;
 /// Schema is response struct from hashicorp vault when we hit /v1/secret/path
 pub mod schema;
+/// Wrapper type that keeps secrets like the Vault token out of logs and Debug output
+pub mod sensitive;
 pub use client::Vault;
+pub use sensitive::SensitiveString;
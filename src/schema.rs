@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
 pub struct VaultSchemaV1<T> {
@@ -9,7 +9,7 @@ pub struct VaultSchemaV1<T> {
     pub data: T,
     pub wrap_info: Option<String>,
     pub warnings: Option<String>,
-    pub auth: Option<String>,
+    pub auth: Option<Auth>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -21,10 +21,73 @@ pub struct VaultSchemaV2<T> {
     pub data: Data<T>,
     pub wrap_info: Option<String>,
     pub warnings: Option<String>,
-    pub auth: Option<String>,
+    pub auth: Option<Auth>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Data<T> {
     pub data: T,
 }
+
+/// Request body for `/v1/transit/encrypt/{key}`.
+#[derive(Debug, Serialize)]
+pub struct TransitEncryptRequest {
+    pub plaintext: String,
+}
+
+/// `data` field returned by `/v1/transit/encrypt/{key}`.
+#[derive(Debug, Deserialize)]
+pub struct TransitEncryptData {
+    pub ciphertext: String,
+}
+
+/// Request body for `/v1/transit/decrypt/{key}`.
+#[derive(Debug, Serialize)]
+pub struct TransitDecryptRequest {
+    pub ciphertext: String,
+}
+
+/// `data` field returned by `/v1/transit/decrypt/{key}`.
+#[derive(Debug, Deserialize)]
+pub struct TransitDecryptData {
+    pub plaintext: String,
+}
+
+/// `auth` block returned alongside a secret response or an auth method login.
+#[derive(Debug, Deserialize)]
+pub struct Auth {
+    pub client_token: String,
+    pub lease_duration: u32,
+    pub renewable: bool,
+}
+
+/// Response from `/v1/auth/{method}/login`.
+#[derive(Debug, Deserialize)]
+pub struct VaultLoginResponse {
+    pub request_id: String,
+    pub lease_id: String,
+    pub renewable: bool,
+    pub lease_duration: u32,
+    pub auth: Auth,
+}
+
+/// Request body for `/v1/auth/approle/login`.
+#[derive(Debug, Serialize)]
+pub struct AppRoleLoginRequest {
+    pub role_id: String,
+    pub secret_id: String,
+}
+
+/// Request body for `/v1/auth/kubernetes/login`.
+#[derive(Debug, Serialize)]
+pub struct KubernetesLoginRequest {
+    pub role: String,
+    pub jwt: String,
+}
+
+/// Request body for `/v1/auth/jwt/login`.
+#[derive(Debug, Serialize)]
+pub struct JwtLoginRequest {
+    pub role: String,
+    pub jwt: String,
+}
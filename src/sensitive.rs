@@ -0,0 +1,58 @@
+use std::fmt;
+
+/// Wraps a secret value (e.g. a Vault token) so it can't end up in logs or traces by accident.
+///
+/// `Display` and `Debug` both render `***REDACTED***` regardless of the wrapped value; use
+/// [`SensitiveString::expose`] to get at the real value when building a request.
+#[derive(Clone)]
+pub struct SensitiveString(String);
+
+impl SensitiveString {
+    /// Returns the wrapped value. Only call this right before it's needed (e.g. to set a
+    /// request header); never log or `Debug`-print the result.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SensitiveString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "***REDACTED***")
+    }
+}
+
+impl fmt::Display for SensitiveString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "***REDACTED***")
+    }
+}
+
+impl From<String> for SensitiveString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SensitiveString {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_and_display_redact_the_value() {
+        let secret = SensitiveString::from("s.super-secret-token");
+        assert_eq!(format!("{:?}", secret), "***REDACTED***");
+        assert_eq!(format!("{}", secret), "***REDACTED***");
+    }
+
+    #[test]
+    fn expose_returns_the_wrapped_value() {
+        let secret = SensitiveString::from("s.super-secret-token");
+        assert_eq!(secret.expose(), "s.super-secret-token");
+    }
+}